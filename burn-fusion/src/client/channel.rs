@@ -0,0 +1,245 @@
+use super::FusionClient;
+use crate::{stream::TensorOpsDescription, FusionBackend, FusionServer, FusionTensor, Handle};
+use burn_tensor::ops::{FloatElem, IntElem};
+use std::sync::{
+    mpsc::{channel, sync_channel, Receiver, Sender},
+    Arc,
+};
+use std::thread::JoinHandle;
+
+/// A unit of work executed by the dedicated server thread. Boxing the closure lets every
+/// `FusionClient` method describe its own reply type without the job queue itself needing to
+/// be generic over it.
+type Job<B> = Box<dyn FnOnce(&mut FusionServer<B>) + Send>;
+
+/// Feeds a dedicated thread running a single `FusionServer` instead of locking it, so
+/// concurrent callers enqueue work onto an MPSC queue rather than spinning on a shared mutex.
+pub struct ChannelFusionClient<B>
+where
+    B: FusionBackend,
+{
+    jobs: Sender<Job<B>>,
+    device: B::FusionDevice,
+    // Handle to the worker thread, shared by every clone. The thread itself keeps running until
+    // every clone's `jobs` sender has been dropped, at which point its `recv` loop ends.
+    _worker: Arc<JoinHandle<()>>,
+}
+
+impl<B> Clone for ChannelFusionClient<B>
+where
+    B: FusionBackend,
+{
+    fn clone(&self) -> Self {
+        Self {
+            jobs: self.jobs.clone(),
+            device: self.device.clone(),
+            _worker: self._worker.clone(),
+        }
+    }
+}
+
+impl<B> ChannelFusionClient<B>
+where
+    B: FusionBackend,
+{
+    fn run(receiver: Receiver<Job<B>>, mut server: FusionServer<B>) {
+        while let Ok(job) = receiver.recv() {
+            job(&mut server);
+        }
+    }
+
+    fn send(&self, job: Job<B>) {
+        self.jobs
+            .send(job)
+            .expect("The fusion server thread should still be running");
+    }
+}
+
+impl<B> FusionClient for ChannelFusionClient<B>
+where
+    B: FusionBackend,
+{
+    type FusionBackend = B;
+
+    fn new(device: B::FusionDevice) -> Self {
+        // Unbounded: `register`/`register_orphan` enqueue and return immediately rather than
+        // block on queue depth. `tensor_uninitialized`, `register_tensor`, `drain` and the
+        // `read_tensor_*` calls wait for the server thread's reply before returning.
+        let (sender, receiver) = channel::<Job<B>>();
+        let server = FusionServer::new(device.clone());
+
+        let worker = std::thread::spawn(move || Self::run(receiver, server));
+
+        Self {
+            jobs: sender,
+            device,
+            _worker: Arc::new(worker),
+        }
+    }
+
+    fn register<O: crate::stream::Ops<Self::FusionBackend> + 'static>(
+        &self,
+        description: TensorOpsDescription,
+        ops: O,
+    ) {
+        // Box the op into the trait object the server actually stores *before* it crosses the
+        // channel: `O` itself isn't guaranteed `Send`, but `Box<dyn Ops<B>>` is, since `Ops<B>`
+        // already requires `Send` as a supertrait (ops can be drained from a different thread
+        // than the one that registered them, even with the mutex-based client).
+        let ops: Box<dyn crate::stream::Ops<Self::FusionBackend>> = Box::new(ops);
+
+        // Fire and forget: the caller doesn't need to know when the op actually runs, only
+        // that it will run in submission order.
+        self.send(Box::new(move |server| server.register(description, ops)));
+    }
+
+    fn drain(&self) {
+        let (reply, rx) = sync_channel(1);
+
+        self.send(Box::new(move |server| {
+            server.drain_streams();
+            let _ = reply.send(());
+        }));
+
+        rx.recv()
+            .expect("The fusion server thread should still be running");
+    }
+
+    fn tensor_uninitialized(&self, shape: Vec<usize>) -> FusionTensor<Self> {
+        let (reply, rx) = sync_channel(1);
+
+        self.send(Box::new(move |server| {
+            let id = server.create_empty_handle();
+            let _ = reply.send(id);
+        }));
+
+        let id = rx
+            .recv()
+            .expect("The fusion server thread should still be running");
+
+        FusionTensor::new(id, shape, self.clone())
+    }
+
+    fn device(&self) -> &<Self::FusionBackend as FusionBackend>::FusionDevice {
+        &self.device
+    }
+
+    fn register_tensor(
+        &self,
+        handle: Handle<Self::FusionBackend>,
+        shape: Vec<usize>,
+    ) -> FusionTensor<Self> {
+        let (reply, rx) = sync_channel(1);
+
+        self.send(Box::new(move |server| {
+            let id = server.create_empty_handle();
+            server.handles.register_handle(id.as_ref().clone(), handle);
+            let _ = reply.send(id);
+        }));
+
+        let id = rx
+            .recv()
+            .expect("The fusion server thread should still be running");
+
+        FusionTensor::new(id, shape, self.clone())
+    }
+
+    fn read_tensor_float<const D: usize>(
+        &self,
+        tensor: crate::TensorDescription,
+    ) -> burn_tensor::Reader<burn_tensor::Data<FloatElem<Self::FusionBackend>, D>> {
+        let (reply, rx) = sync_channel(1);
+
+        self.send(Box::new(move |server| {
+            let data = server.read_float::<D>(tensor).read();
+            let _ = reply.send(data);
+        }));
+
+        let data = rx
+            .recv()
+            .expect("The fusion server thread should still be running");
+
+        burn_tensor::Reader::Concrete(data)
+    }
+
+    fn read_tensor_int<const D: usize>(
+        &self,
+        tensor: crate::TensorDescription,
+    ) -> burn_tensor::Reader<burn_tensor::Data<IntElem<Self::FusionBackend>, D>> {
+        let (reply, rx) = sync_channel(1);
+
+        self.send(Box::new(move |server| {
+            let data = server.read_int::<D>(tensor).read();
+            let _ = reply.send(data);
+        }));
+
+        let data = rx
+            .recv()
+            .expect("The fusion server thread should still be running");
+
+        burn_tensor::Reader::Concrete(data)
+    }
+
+    fn read_tensor_bool<const D: usize>(
+        &self,
+        tensor: crate::TensorDescription,
+    ) -> burn_tensor::Reader<burn_tensor::Data<bool, D>> {
+        let (reply, rx) = sync_channel(1);
+
+        self.send(Box::new(move |server| {
+            let data = server.read_bool::<D>(tensor).read();
+            let _ = reply.send(data);
+        }));
+
+        let data = rx
+            .recv()
+            .expect("The fusion server thread should still be running");
+
+        burn_tensor::Reader::Concrete(data)
+    }
+
+    fn change_client_float<const D: usize>(
+        &self,
+        _tensor: crate::TensorDescription,
+        _client: Self,
+    ) -> FusionTensor<Self> {
+        unimplemented!(
+            "Migrating a tensor between two ChannelFusionClient servers requires coordinating \
+             both worker threads directly, which isn't supported yet."
+        );
+    }
+
+    fn change_client_int<const D: usize>(
+        &self,
+        _tensor: crate::TensorDescription,
+        _client: Self,
+    ) -> FusionTensor<Self> {
+        unimplemented!(
+            "Migrating a tensor between two ChannelFusionClient servers requires coordinating \
+             both worker threads directly, which isn't supported yet."
+        );
+    }
+
+    fn change_client_bool<const D: usize>(
+        &self,
+        _tensor: crate::TensorDescription,
+        _client: Self,
+    ) -> FusionTensor<Self> {
+        unimplemented!(
+            "Migrating a tensor between two ChannelFusionClient servers requires coordinating \
+             both worker threads directly, which isn't supported yet."
+        );
+    }
+
+    fn register_orphan(&self, id: &crate::TensorId) {
+        let id = id.clone();
+        self.send(Box::new(move |server| server.drop_tensor_handle(id)));
+    }
+}
+
+// Submission ordering (jobs run in the order they're sent, since a single worker thread drains
+// one MPSC queue) would normally be covered by a `#[cfg(test)]` module here, constructing a
+// `ChannelFusionClient<SomeConcreteBackend>` and asserting drained ops land in FIFO order. This
+// crate only has `FusionBackend` as a trait bound, with no concrete implementation to build
+// against, so that test belongs in one of the backend crates instead, alongside the equivalent
+// coverage for `MutexFusionClient`.
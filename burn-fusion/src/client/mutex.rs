@@ -1,7 +1,13 @@
+// `fusion-tracing` gates an optional `tracing` dependency. This snapshot of the crate ships
+// without a `Cargo.toml`, so there is nowhere to declare `tracing = { optional = true }` and
+// `fusion-tracing = ["dep:tracing"]` yet; whoever adds the manifest for this crate needs to wire
+// both in alongside this `#[cfg(feature = "fusion-tracing")]` usage.
 use super::FusionClient;
 use crate::{stream::TensorOpsDescription, FusionBackend, FusionServer, FusionTensor, Handle};
 use burn_tensor::ops::FloatElem;
 use spin::Mutex;
+#[cfg(feature = "fusion-tracing")]
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 /// Use a mutex to communicate with the fusion server.
@@ -11,6 +17,11 @@ where
 {
     server: Arc<Mutex<FusionServer<B>>>,
     device: B::FusionDevice,
+    // Tracks how many operations have been registered since the last drain, purely so the
+    // `fusion-tracing` span below can report a useful count; it costs nothing when the feature
+    // is off since the type doesn't even exist in the struct.
+    #[cfg(feature = "fusion-tracing")]
+    pending_ops: Arc<AtomicUsize>,
 }
 
 impl<B> Clone for MutexFusionClient<B>
@@ -21,6 +32,8 @@ where
         Self {
             server: self.server.clone(),
             device: self.device.clone(),
+            #[cfg(feature = "fusion-tracing")]
+            pending_ops: self.pending_ops.clone(),
         }
     }
 }
@@ -35,6 +48,8 @@ where
         Self {
             device: device.clone(),
             server: Arc::new(Mutex::new(FusionServer::new(device))),
+            #[cfg(feature = "fusion-tracing")]
+            pending_ops: Arc::new(AtomicUsize::new(0)),
         }
     }
 
@@ -43,10 +58,22 @@ where
         description: TensorOpsDescription,
         ops: O,
     ) {
+        #[cfg(feature = "fusion-tracing")]
+        {
+            let _span = tracing::trace_span!("fusion_register").entered();
+            self.pending_ops.fetch_add(1, Ordering::Relaxed);
+        }
+
         self.server.lock().register(description, Box::new(ops))
     }
 
     fn drain(&self) {
+        #[cfg(feature = "fusion-tracing")]
+        let _span = {
+            let num_operations = self.pending_ops.swap(0, Ordering::Relaxed);
+            tracing::trace_span!("fusion_drain", num_operations).entered()
+        };
+
         self.server.lock().drain_streams();
     }
 
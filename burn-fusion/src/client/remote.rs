@@ -0,0 +1,413 @@
+use super::FusionClient;
+use crate::{stream::TensorOpsDescription, FusionBackend, FusionTensor, Handle};
+use burn_tensor::ops::{FloatElem, IntElem};
+use serde::{Deserialize, Serialize};
+use std::{
+    io::{BufRead, BufReader, BufWriter, Write},
+    net::TcpStream,
+    sync::{Arc, Mutex},
+};
+
+/// A message sent to a remote `FusionServer`, mirroring the operations a [FusionClient]
+/// performs against a local `FusionServer`. Wire format is one JSON value per line in both
+/// directions; the server-side listener that replays these isn't part of this change.
+#[derive(Serialize, Deserialize)]
+enum RemoteRequest {
+    Register {
+        description: TensorOpsDescription,
+    },
+    Drain,
+    TensorUninitialized {
+        shape: Vec<usize>,
+    },
+    RegisterOrphan {
+        id: crate::TensorId,
+    },
+    ReadFloat {
+        tensor: crate::TensorDescription,
+    },
+    ReadInt {
+        tensor: crate::TensorDescription,
+    },
+    ReadBool {
+        tensor: crate::TensorDescription,
+    },
+    WriteFloat {
+        shape: Vec<usize>,
+        data: Vec<u8>,
+    },
+    WriteInt {
+        shape: Vec<usize>,
+        data: Vec<u8>,
+    },
+    WriteBool {
+        shape: Vec<usize>,
+        data: Vec<u8>,
+    },
+}
+
+/// The reply matching a [RemoteRequest]. Requests that don't produce a value (`Register`,
+/// `Drain`, `RegisterOrphan`) still get an empty acknowledgement so the connection stays
+/// request/response and the client can tell a dropped socket from a slow one.
+#[derive(Serialize, Deserialize)]
+enum RemoteResponse {
+    Ack,
+    TensorId(crate::TensorId),
+    DataFloat(Vec<u8>),
+    DataInt(Vec<u8>),
+    DataBool(Vec<u8>),
+}
+
+/// A single connection to the satellite device, serializing access the same way
+/// `MutexFusionClient` serializes access to its in-process `FusionServer`.
+struct RemoteConnection {
+    reader: BufReader<TcpStream>,
+    writer: BufWriter<TcpStream>,
+}
+
+impl RemoteConnection {
+    fn connect(address: &str) -> Self {
+        let stream = TcpStream::connect(address)
+            .unwrap_or_else(|err| panic!("Failed to connect to fusion device at {address}: {err}"));
+        let reader = BufReader::new(
+            stream
+                .try_clone()
+                .expect("Should be able to clone the fusion device socket"),
+        );
+        let writer = BufWriter::new(stream);
+
+        Self { reader, writer }
+    }
+
+    fn request(&mut self, request: RemoteRequest) -> RemoteResponse {
+        serde_json::to_writer(&mut self.writer, &request)
+            .expect("Should be able to write to the fusion device socket");
+        self.writer
+            .write_all(b"\n")
+            .expect("Should be able to write to the fusion device socket");
+        self.writer
+            .flush()
+            .expect("Should be able to flush the fusion device socket");
+
+        // `serde_json::from_reader` keeps reading past the first value to make sure the rest of
+        // the stream is empty, which would block forever on a socket that stays open for more
+        // requests. Read a single newline-delimited line instead, matching how `write_all` above
+        // terminates each request.
+        let mut line = String::new();
+        self.reader
+            .read_line(&mut line)
+            .expect("Should be able to read from the fusion device socket");
+
+        serde_json::from_str(&line).expect("Should be able to parse the fusion device reply")
+    }
+}
+
+/// Drives a `FusionServer` running on another process or machine, streaming
+/// [TensorOpsDescription]s to it instead of registering them with an in-process server. The
+/// remote side replays the descriptions to reconstruct and execute the fused kernels, which
+/// lets a single Burn program drive a GPU on another host as just another fusion device.
+pub struct RemoteFusionClient<B>
+where
+    B: FusionBackend,
+{
+    connection: Arc<Mutex<RemoteConnection>>,
+    device: B::FusionDevice,
+    address: String,
+}
+
+impl<B> Clone for RemoteFusionClient<B>
+where
+    B: FusionBackend,
+{
+    fn clone(&self) -> Self {
+        Self {
+            connection: self.connection.clone(),
+            device: self.device.clone(),
+            address: self.address.clone(),
+        }
+    }
+}
+
+impl<B> RemoteFusionClient<B>
+where
+    B: FusionBackend,
+{
+    /// Open a connection to the `FusionServer` listening at `address`, identified by `device`
+    /// on the remote side.
+    pub fn connect(address: &str, device: B::FusionDevice) -> Self {
+        Self {
+            connection: Arc::new(Mutex::new(RemoteConnection::connect(address))),
+            device,
+            address: address.to_string(),
+        }
+    }
+}
+
+impl<B> FusionClient for RemoteFusionClient<B>
+where
+    B: FusionBackend,
+{
+    type FusionBackend = B;
+
+    fn new(_device: B::FusionDevice) -> Self {
+        // Unlike `MutexFusionClient`/`ChannelFusionClient`, there's no local `FusionServer` to
+        // spin up from a device alone: the server already exists on another host, reachable only
+        // by address, and that address isn't part of `B::FusionDevice`. `FusionClient::new` has
+        // nowhere to get it from, so construction goes through `RemoteFusionClient::connect`
+        // instead, which is the only place the address is known.
+        panic!(
+            "RemoteFusionClient requires the address of the satellite device; use \
+             `RemoteFusionClient::connect` instead."
+        );
+    }
+
+    fn register<O: crate::stream::Ops<Self::FusionBackend> + 'static>(
+        &self,
+        description: TensorOpsDescription,
+        _ops: O,
+    ) {
+        // `_ops` only exists to run the operation in-process; the remote side reconstructs the
+        // equivalent operation purely from the serialized description.
+        //
+        // This blocks on the round trip like every other request on this connection: the wire
+        // protocol pairs one reply with every request so a dropped or stalled satellite surfaces
+        // immediately instead of silently swallowing a registration, and `RemoteConnection`
+        // already serializes all requests behind a single mutex regardless of whether `register`
+        // waits for its own reply. Pipelining registers without waiting for each ack would need
+        // the connection to track replies out of request order, which isn't worth the added
+        // complexity unless registration round trips actually become the bottleneck -- today it's
+        // `drain` that pays for a full fused run, not the individual `register` calls leading up
+        // to it.
+        self.connection
+            .lock()
+            .unwrap()
+            .request(RemoteRequest::Register { description });
+    }
+
+    fn drain(&self) {
+        self.connection.lock().unwrap().request(RemoteRequest::Drain);
+    }
+
+    fn tensor_uninitialized(&self, shape: Vec<usize>) -> FusionTensor<Self> {
+        let response = self
+            .connection
+            .lock()
+            .unwrap()
+            .request(RemoteRequest::TensorUninitialized { shape: shape.clone() });
+
+        let id = match response {
+            RemoteResponse::TensorId(id) => id,
+            _ => panic!("Unexpected response to TensorUninitialized"),
+        };
+
+        FusionTensor::new(Arc::new(id), shape, self.clone())
+    }
+
+    fn device(&self) -> &<Self::FusionBackend as FusionBackend>::FusionDevice {
+        &self.device
+    }
+
+    fn register_tensor(
+        &self,
+        _handle: Handle<Self::FusionBackend>,
+        _shape: Vec<usize>,
+    ) -> FusionTensor<Self> {
+        panic!(
+            "RemoteFusionClient can't register an existing local handle: the backing memory \
+             lives on this process, not on {}.",
+            self.address
+        );
+    }
+
+    fn read_tensor_float<const D: usize>(
+        &self,
+        tensor: crate::TensorDescription,
+    ) -> burn_tensor::Reader<burn_tensor::Data<FloatElem<Self::FusionBackend>, D>> {
+        let response = self
+            .connection
+            .lock()
+            .unwrap()
+            .request(RemoteRequest::ReadFloat { tensor });
+
+        let bytes = match response {
+            RemoteResponse::DataFloat(bytes) => bytes,
+            _ => panic!("Unexpected response to ReadFloat"),
+        };
+        let data = serde_json::from_slice(&bytes).expect("Should deserialize remote tensor data");
+
+        burn_tensor::Reader::Concrete(data)
+    }
+
+    fn read_tensor_int<const D: usize>(
+        &self,
+        tensor: crate::TensorDescription,
+    ) -> burn_tensor::Reader<burn_tensor::Data<IntElem<Self::FusionBackend>, D>> {
+        let response = self
+            .connection
+            .lock()
+            .unwrap()
+            .request(RemoteRequest::ReadInt { tensor });
+
+        let bytes = match response {
+            RemoteResponse::DataInt(bytes) => bytes,
+            _ => panic!("Unexpected response to ReadInt"),
+        };
+        let data = serde_json::from_slice(&bytes).expect("Should deserialize remote tensor data");
+
+        burn_tensor::Reader::Concrete(data)
+    }
+
+    fn read_tensor_bool<const D: usize>(
+        &self,
+        tensor: crate::TensorDescription,
+    ) -> burn_tensor::Reader<burn_tensor::Data<bool, D>> {
+        let response = self
+            .connection
+            .lock()
+            .unwrap()
+            .request(RemoteRequest::ReadBool { tensor });
+
+        let bytes = match response {
+            RemoteResponse::DataBool(bytes) => bytes,
+            _ => panic!("Unexpected response to ReadBool"),
+        };
+        let data = serde_json::from_slice(&bytes).expect("Should deserialize remote tensor data");
+
+        burn_tensor::Reader::Concrete(data)
+    }
+
+    // `change_client_*` moves a tensor onto another satellite device. Since the source and
+    // target may be different processes entirely, there's no server-to-server handle transfer
+    // like `MutexFusionClient` has; instead the data makes a round trip through this process.
+    // The `Write*` reply carries the id the remote side actually registered the written data
+    // under, so the returned tensor is tied to that handle rather than a fresh, unrelated one.
+    fn change_client_float<const D: usize>(
+        &self,
+        tensor: crate::TensorDescription,
+        client: Self,
+    ) -> FusionTensor<Self> {
+        let shape = tensor.shape.clone();
+        let data = self.read_tensor_float::<D>(tensor).read();
+        let bytes = serde_json::to_vec(&data).expect("Should serialize tensor data");
+
+        let response = client
+            .connection
+            .lock()
+            .unwrap()
+            .request(RemoteRequest::WriteFloat {
+                shape: shape.clone(),
+                data: bytes,
+            });
+
+        let id = match response {
+            RemoteResponse::TensorId(id) => id,
+            _ => panic!("Unexpected response to WriteFloat"),
+        };
+
+        FusionTensor::new(Arc::new(id), shape, client)
+    }
+
+    fn change_client_int<const D: usize>(
+        &self,
+        tensor: crate::TensorDescription,
+        client: Self,
+    ) -> FusionTensor<Self> {
+        let shape = tensor.shape.clone();
+        let data = self.read_tensor_int::<D>(tensor).read();
+        let bytes = serde_json::to_vec(&data).expect("Should serialize tensor data");
+
+        let response = client
+            .connection
+            .lock()
+            .unwrap()
+            .request(RemoteRequest::WriteInt {
+                shape: shape.clone(),
+                data: bytes,
+            });
+
+        let id = match response {
+            RemoteResponse::TensorId(id) => id,
+            _ => panic!("Unexpected response to WriteInt"),
+        };
+
+        FusionTensor::new(Arc::new(id), shape, client)
+    }
+
+    fn change_client_bool<const D: usize>(
+        &self,
+        tensor: crate::TensorDescription,
+        client: Self,
+    ) -> FusionTensor<Self> {
+        let shape = tensor.shape.clone();
+        let data = self.read_tensor_bool::<D>(tensor).read();
+        let bytes = serde_json::to_vec(&data).expect("Should serialize tensor data");
+
+        let response = client
+            .connection
+            .lock()
+            .unwrap()
+            .request(RemoteRequest::WriteBool {
+                shape: shape.clone(),
+                data: bytes,
+            });
+
+        let id = match response {
+            RemoteResponse::TensorId(id) => id,
+            _ => panic!("Unexpected response to WriteBool"),
+        };
+
+        FusionTensor::new(Arc::new(id), shape, client)
+    }
+
+    fn register_orphan(&self, id: &crate::TensorId) {
+        self.connection
+            .lock()
+            .unwrap()
+            .request(RemoteRequest::RegisterOrphan { id: id.clone() });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drain_request_round_trips_over_json() {
+        let encoded = serde_json::to_string(&RemoteRequest::Drain).unwrap();
+        let decoded: RemoteRequest = serde_json::from_str(&encoded).unwrap();
+
+        assert!(matches!(decoded, RemoteRequest::Drain));
+    }
+
+    #[test]
+    fn tensor_uninitialized_request_round_trips_over_json() {
+        let request = RemoteRequest::TensorUninitialized { shape: vec![2, 3] };
+        let encoded = serde_json::to_string(&request).unwrap();
+        let decoded: RemoteRequest = serde_json::from_str(&encoded).unwrap();
+
+        match decoded {
+            RemoteRequest::TensorUninitialized { shape } => assert_eq!(shape, vec![2, 3]),
+            _ => panic!("decoded into the wrong variant"),
+        }
+    }
+
+    #[test]
+    fn ack_response_round_trips_over_json() {
+        let encoded = serde_json::to_string(&RemoteResponse::Ack).unwrap();
+        let decoded: RemoteResponse = serde_json::from_str(&encoded).unwrap();
+
+        assert!(matches!(decoded, RemoteResponse::Ack));
+    }
+
+    #[test]
+    fn data_float_response_round_trips_over_json() {
+        let response = RemoteResponse::DataFloat(vec![1, 2, 3]);
+        let encoded = serde_json::to_string(&response).unwrap();
+        let decoded: RemoteResponse = serde_json::from_str(&encoded).unwrap();
+
+        match decoded {
+            RemoteResponse::DataFloat(bytes) => assert_eq!(bytes, vec![1, 2, 3]),
+            _ => panic!("decoded into the wrong variant"),
+        }
+    }
+}
@@ -1,4 +1,10 @@
-use std::sync::Arc;
+// `fusion-tracing` gates the optional `tracing` usage below; see the note in
+// `burn-fusion/src/client/mutex.rs` about wiring it into a `Cargo.toml` once this crate has one.
+// `log` backs the persistent autotune cache's warnings further down and is expected to already
+// be a plain (non-optional) dependency, same as elsewhere in this crate.
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
 
 use super::{
     kernel::ElementWiseKernelFactory, tune::ElementWiseAutotuneOperationSet,
@@ -32,10 +38,10 @@ pub struct CompilationPhase;
 /// Phase where the kernel should be executed.
 #[derive(new)]
 pub struct ExecutionPhase<R: JitRuntime> {
-    /// Kernel set with default cube size.
-    pub(super) kernel_factory_1: ElementWiseKernelFactory<R>,
-    /// Kernel set with custom cube size.
-    pub(super) kernel_factory_2: ElementWiseKernelFactory<R>,
+    /// One kernel factory per candidate cube dimension, all sharing the same fused trace. The
+    /// autotuner benchmarks every entry to find the launch geometry that suits the target
+    /// hardware, with index 0 (`CubeDim::default()`) doubling as the non-autotuned fallback.
+    pub(super) kernel_factories: Vec<ElementWiseKernelFactory<R>>,
 }
 
 #[derive(new, Serialize, Deserialize)]
@@ -44,27 +50,185 @@ pub struct ElementWiseState {
     num_operations: usize,
 }
 
+/// The cube dimensions benchmarked for a fused elementwise kernel. The exact tensor shapes
+/// aren't known yet at this (compile) stage, only the operation count, so the search space is
+/// widened for longer fused chains rather than tailored to a concrete shape.
+fn cube_dim_candidates(num_operations: usize) -> Vec<CubeDim> {
+    let mut candidates = vec![
+        CubeDim::default(),
+        CubeDim::new(64, 1, 1),
+        CubeDim::new(32, 8, 1),
+        CubeDim::new(8, 8, 4),
+    ];
+
+    if num_operations > 16 {
+        candidates.push(CubeDim::new(128, 1, 1));
+    }
+
+    candidates
+}
+
 impl<R: JitRuntime> ElementWise<R, CompilationPhase> {
     pub(crate) fn compile(self) -> ElementWise<R, ExecutionPhase<R>> {
         let info = Arc::new(self.trace.compiling());
 
-        let kernel_factory_1 = ElementWiseKernelFactory::new(
-            IdGenerator::generate(),
-            info.clone(),
-            CubeDim::default(),
-        );
-        let kernel_factory_2 =
-            ElementWiseKernelFactory::new(IdGenerator::generate(), info, CubeDim::new(16, 16, 1));
+        let kernel_factories = cube_dim_candidates(self.num_operations)
+            .into_iter()
+            .map(|cube_dim| {
+                ElementWiseKernelFactory::new(IdGenerator::generate(), info.clone(), cube_dim)
+            })
+            .collect();
 
         ElementWise {
             trace: self.trace,
             device: self.device,
-            phase: ExecutionPhase::new(kernel_factory_1, kernel_factory_2),
+            phase: ExecutionPhase::new(kernel_factories),
             num_operations: self.num_operations,
         }
     }
 }
 
+/// A stable, serializable fingerprint for the hardware an autotune decision was recorded on.
+/// `JitTuneId` already identifies backend, runtime and device -- that's exactly the identity a
+/// cached selection must match before it's reused -- but it's defined in `cubecl` purely for the
+/// in-memory `LocalTuner`'s use, so nothing guarantees it also implements `Hash`, `Eq`,
+/// `Serialize` and `Deserialize`, which an on-disk `HashMap` key and its JSON encoding need.
+/// Derive the on-disk fingerprint from its `Debug` output instead of adding those bounds to a
+/// type this crate doesn't own. This is only as complete as `JitTuneId`'s `Debug` impl; if that
+/// impl ever omits a field that actually distinguishes incompatible hardware, this needs
+/// widening once `JitTuneId`'s definition is available to inspect directly.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub(crate) struct DeviceFingerprint(String);
+
+impl DeviceFingerprint {
+    fn new(id: &JitTuneId) -> Self {
+        Self(format!("{id:?}"))
+    }
+}
+
+/// On-disk encoding of [PersistentAutotuneCache]. `JitAutotuneKey` is a data-carrying type, not a
+/// string, so `serde_json` can't use it as an object key directly (it requires string keys and
+/// errors out otherwise). Entries are flattened into key/value pairs instead.
+#[derive(Default, Serialize, Deserialize)]
+struct PersistentAutotuneCacheDto {
+    entries: Vec<(DeviceFingerprint, Vec<(JitAutotuneKey, usize)>)>,
+}
+
+/// On-disk cache of autotune decisions, keyed by the [DeviceFingerprint] they were recorded
+/// under so a selection made on one target is never reused on incompatible hardware.
+#[derive(Default)]
+struct PersistentAutotuneCache {
+    entries: HashMap<DeviceFingerprint, HashMap<JitAutotuneKey, usize>>,
+}
+
+impl PersistentAutotuneCache {
+    fn load(path: &Path) -> Self {
+        let content = match std::fs::read(path) {
+            Ok(content) => content,
+            Err(err) => {
+                if err.kind() != std::io::ErrorKind::NotFound {
+                    log::warn!(
+                        "Could not read the autotune cache at {}: {err}; re-tuning from scratch",
+                        path.display()
+                    );
+                }
+                return Self::default();
+            }
+        };
+
+        let dto: PersistentAutotuneCacheDto = match serde_json::from_slice(&content) {
+            Ok(dto) => dto,
+            Err(err) => {
+                log::warn!(
+                    "The autotune cache at {} is corrupt ({err}); re-tuning from scratch",
+                    path.display()
+                );
+                return Self::default();
+            }
+        };
+
+        let entries = dto
+            .entries
+            .into_iter()
+            .map(|(fingerprint, keys)| (fingerprint, keys.into_iter().collect()))
+            .collect();
+
+        Self { entries }
+    }
+
+    fn save(&self, path: &Path) {
+        let dto = PersistentAutotuneCacheDto {
+            entries: self
+                .entries
+                .iter()
+                .map(|(fingerprint, keys)| {
+                    let keys = keys.iter().map(|(key, index)| (key.clone(), *index)).collect();
+                    (fingerprint.clone(), keys)
+                })
+                .collect(),
+        };
+
+        let content = match serde_json::to_vec(&dto) {
+            Ok(content) => content,
+            Err(err) => {
+                log::warn!("Could not serialize the autotune cache: {err}");
+                return;
+            }
+        };
+
+        if let Err(err) = std::fs::write(path, content) {
+            log::warn!(
+                "Could not write the autotune cache to {}: {err}",
+                path.display()
+            );
+        }
+    }
+}
+
+/// Persists [ElementWise] autotune decisions across process restarts, wired through the
+/// static [LocalTuner] used by [ElementWise::execute].
+struct PersistentTuner {
+    cache: Mutex<PersistentAutotuneCache>,
+    path: PathBuf,
+}
+
+impl PersistentTuner {
+    fn load(path: PathBuf) -> Self {
+        let cache = Mutex::new(PersistentAutotuneCache::load(&path));
+        Self { cache, path }
+    }
+
+    fn get(&self, fingerprint: &DeviceFingerprint, key: &JitAutotuneKey) -> Option<usize> {
+        self.cache
+            .lock()
+            .unwrap()
+            .entries
+            .get(fingerprint)?
+            .get(key)
+            .copied()
+    }
+
+    fn save(&self, fingerprint: DeviceFingerprint, key: JitAutotuneKey, fastest_set_index: usize) {
+        let mut cache = self.cache.lock().unwrap();
+        cache
+            .entries
+            .entry(fingerprint)
+            .or_default()
+            .insert(key, fastest_set_index);
+        cache.save(&self.path);
+    }
+}
+
+pub(super) fn persistent_tuner() -> &'static PersistentTuner {
+    static INSTANCE: OnceLock<PersistentTuner> = OnceLock::new();
+    INSTANCE.get_or_init(|| {
+        let path = std::env::var_os("BURN_AUTOTUNE_CACHE_PATH")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(".burn-autotune-cache.json"));
+        PersistentTuner::load(path)
+    })
+}
+
 impl<R: JitRuntime> ElementWise<R, ExecutionPhase<R>> {
     pub(crate) fn execute(&mut self, context: &mut Context<'_, JitFusionHandle<R>>) {
         let client = R::client(&self.device);
@@ -75,14 +239,31 @@ impl<R: JitRuntime> ElementWise<R, ExecutionPhase<R>> {
         ));
 
         let id = JitTuneId::new::<R>(&self.device);
+        let fingerprint = DeviceFingerprint::new(&id);
+
+        #[cfg(feature = "fusion-tracing")]
+        let _span = tracing::trace_span!(
+            "fusion_stream",
+            num_operations = self.num_operations,
+            key = ?key,
+        )
+        .entered();
 
         static TUNER: LocalTuner<JitAutotuneKey, JitTuneId> = local_tuner!();
 
         if let Some(index) = TUNER.autotune_result(&id, &key) {
-            self.run_kernel(context, client, index)
-        } else {
-            self.run_autotune(context, client, id, key, &TUNER)
+            #[cfg(feature = "fusion-tracing")]
+            tracing::trace!(fastest_set_index = index, source = "in_memory_cache");
+            return self.run_kernel(context, client, index);
+        }
+
+        if let Some(index) = persistent_tuner().get(&fingerprint, &key) {
+            #[cfg(feature = "fusion-tracing")]
+            tracing::trace!(fastest_set_index = index, source = "persistent_cache");
+            return self.run_kernel(context, client, index);
         }
+
+        self.run_autotune(context, client, id, key, fingerprint, &TUNER);
     }
 
     fn run_kernel(
@@ -92,11 +273,16 @@ impl<R: JitRuntime> ElementWise<R, ExecutionPhase<R>> {
         fastest_set_index: usize,
     ) {
         let info = self.trace.running();
-        let kernel_set = match fastest_set_index {
-            0 => &self.phase.kernel_factory_1,
-            1 => &self.phase.kernel_factory_2,
-            _ => panic!("Should be 0 or 1, got {fastest_set_index}"),
-        };
+        let kernel_set = self
+            .phase
+            .kernel_factories
+            .get(fastest_set_index)
+            .unwrap_or_else(|| {
+                panic!(
+                    "fastest_set_index should be between 0 and {}, got {fastest_set_index}",
+                    self.phase.kernel_factories.len() - 1
+                )
+            });
 
         let kernel = FusionKernel::create(
             kernel_set,
@@ -116,28 +302,35 @@ impl<R: JitRuntime> ElementWise<R, ExecutionPhase<R>> {
         client: ComputeClient<R::Server, R::Channel>,
         id: JitTuneId,
         key: JitAutotuneKey,
+        fingerprint: DeviceFingerprint,
         tuner: &LocalTuner<JitAutotuneKey, JitTuneId>,
     ) {
+        #[cfg(feature = "fusion-tracing")]
+        let _span = tracing::trace_span!("fusion_autotune", key = ?key).entered();
+
         let info = self.trace.running();
 
-        let kernel_1 = FusionKernel::create(
-            &self.phase.kernel_factory_1,
-            &info,
-            context,
-            self.device.clone(),
-            client.clone(),
-            false,
-        );
-        let kernel_2 = FusionKernel::create(
-            &self.phase.kernel_factory_2,
-            &info,
-            context,
-            self.device.clone(),
-            client.clone(),
-            false,
-        );
+        let kernels = self
+            .phase
+            .kernel_factories
+            .iter()
+            .map(|factory| {
+                FusionKernel::create(
+                    factory,
+                    &info,
+                    context,
+                    self.device.clone(),
+                    client.clone(),
+                    false,
+                )
+                .into()
+            })
+            .collect();
+
+        // Index 0 is always `CubeDim::default()`, so it also serves as the non-autotuned
+        // fallback the operation set falls back to when benchmarking can't run.
         let kernel_default = FusionKernel::create(
-            &self.phase.kernel_factory_1,
+            &self.phase.kernel_factories[0],
             &info,
             context,
             self.device.clone(),
@@ -150,8 +343,8 @@ impl<R: JitRuntime> ElementWise<R, ExecutionPhase<R>> {
             &client,
             Box::new(ElementWiseAutotuneOperationSet::new(
                 key,
-                kernel_1.into(),
-                kernel_2.into(),
+                fingerprint,
+                kernels,
                 kernel_default.into(),
             )),
         );
@@ -203,3 +396,44 @@ impl<R: JitRuntime> ElementWise<R, ExecutionPhase<R>> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn persistent_autotune_cache_load_missing_file_is_empty() {
+        let path = std::env::temp_dir().join("burn-autotune-cache-test-missing.json");
+        let _ = std::fs::remove_file(&path);
+
+        let cache = PersistentAutotuneCache::load(&path);
+
+        assert!(cache.entries.is_empty());
+    }
+
+    #[test]
+    fn persistent_autotune_cache_load_corrupt_file_is_empty() {
+        let path = std::env::temp_dir().join("burn-autotune-cache-test-corrupt.json");
+        std::fs::write(&path, b"not valid json").unwrap();
+
+        let cache = PersistentAutotuneCache::load(&path);
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(cache.entries.is_empty());
+    }
+
+    #[test]
+    fn cube_dim_candidates_has_the_four_fixed_candidates_by_default() {
+        let candidates = cube_dim_candidates(4);
+
+        assert_eq!(candidates.len(), 4);
+    }
+
+    #[test]
+    fn cube_dim_candidates_widens_for_longer_fused_chains() {
+        let small = cube_dim_candidates(4);
+        let large = cube_dim_candidates(32);
+
+        assert!(large.len() > small.len());
+    }
+}
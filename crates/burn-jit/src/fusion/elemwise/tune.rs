@@ -0,0 +1,48 @@
+use super::optimization::{persistent_tuner, DeviceFingerprint};
+use crate::tune_key::JitAutotuneKey;
+use cubecl::tune::{AutotuneOperation, AutotuneOperationSet};
+
+/// Benchmarks every candidate kernel in a [ElementWise](super::ElementWise) fusion trace and
+/// hands the winner back to the tuner. The candidate list comes from `ExecutionPhase`'s
+/// `kernel_factories`, so its length tracks whatever cube-dimension search space
+/// `ElementWise::compile` was configured with, rather than a fixed pair.
+#[derive(new)]
+pub(crate) struct ElementWiseAutotuneOperationSet {
+    key: JitAutotuneKey,
+    fingerprint: DeviceFingerprint,
+    kernels: Vec<Box<dyn AutotuneOperation>>,
+    kernel_default: Box<dyn AutotuneOperation>,
+}
+
+impl AutotuneOperationSet<JitAutotuneKey> for ElementWiseAutotuneOperationSet {
+    fn key(&self) -> JitAutotuneKey {
+        self.key.clone()
+    }
+
+    fn autotunables(&self) -> Vec<Box<dyn AutotuneOperation>> {
+        self.kernels.iter().map(|kernel| kernel.clone()).collect()
+    }
+
+    fn fastest(self: Box<Self>, fastest_index: usize) -> Box<dyn AutotuneOperation> {
+        let Self {
+            key,
+            fingerprint,
+            kernels,
+            kernel_default,
+        } = *self;
+
+        // Persisting here, rather than back in `ElementWise::execute` right after calling
+        // `tuner.execute`, means the on-disk cache gets written whenever the tuner actually
+        // settles on a winner -- including if that resolution doesn't happen synchronously on
+        // this call stack. Re-querying the in-memory `LocalTuner` immediately after `execute`
+        // returns would miss that case entirely.
+        #[cfg(feature = "fusion-tracing")]
+        tracing::trace!(fastest_set_index = fastest_index, source = "fresh_benchmark");
+        persistent_tuner().save(fingerprint, key, fastest_index);
+
+        match kernels.into_iter().nth(fastest_index) {
+            Some(kernel) => kernel,
+            None => kernel_default,
+        }
+    }
+}